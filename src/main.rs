@@ -1,15 +1,85 @@
 use actix_web::{web, App, HttpResponse, HttpServer, Responder};
 use base64::{engine::general_purpose, Engine as _};
+use bip39::Mnemonic;
 use bs58;
+use hmac::{Hmac, Mac};
+use mpl_token_metadata::state::Creator;
 use serde::{Deserialize, Serialize};
+use sha2::Sha512;
+use solana_client::{
+    nonblocking::rpc_client::RpcClient, rpc_config::RpcSimulateTransactionConfig,
+};
 use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    message::Message,
     pubkey::Pubkey,
     signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use spl_associated_token_account::{
+    get_associated_token_address,
+    instruction::{create_associated_token_account, create_associated_token_account_idempotent},
 };
-use spl_token::instruction as token_instruction;
+use spl_token::instruction::{self as token_instruction, AuthorityType};
 use std::env;
 use std::str::FromStr;
 
+type HmacSha512 = Hmac<Sha512>;
+
+/// Solana's standard BIP44 derivation path: coin type 501, account 0, change 0.
+const DEFAULT_DERIVATION_PATH: &str = "m/44'/501'/0'/0'";
+
+// Computes HMAC-SHA512(key, data), used throughout SLIP-0010 derivation.
+fn hmac_sha512(key: &[u8], data: &[u8]) -> [u8; 64] {
+    let mut mac = HmacSha512::new_from_slice(key).expect("HMAC accepts a key of any length");
+    mac.update(data);
+    let mut out = [0u8; 64];
+    out.copy_from_slice(&mac.finalize().into_bytes());
+    out
+}
+
+// Parses a derivation path like `m/44'/501'/0'/0'` into its hardened index
+// segments. Solana's ed25519 SLIP-0010 derivation only supports hardened
+// indices, so every segment is treated as hardened regardless of whether
+// it carries a trailing `'`.
+fn parse_derivation_path(path: &str) -> Result<Vec<u32>, String> {
+    path.trim_start_matches("m/")
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| {
+            segment
+                .trim_end_matches('\'')
+                .parse::<u32>()
+                .map_err(|_| format!("Invalid derivation path segment: {}", segment))
+        })
+        .collect()
+}
+
+// Performs SLIP-0010 ed25519 hierarchical derivation over a BIP39 seed,
+// returning the derived 32-byte key and chain code for the given hardened
+// path. See https://github.com/satoshilabs/slips/blob/master/slip-0010.md.
+fn derive_ed25519_slip10(seed: &[u8], path: &[u32]) -> ([u8; 32], [u8; 32]) {
+    let master = hmac_sha512(b"ed25519 seed", seed);
+    let mut key = [0u8; 32];
+    let mut chain_code = [0u8; 32];
+    key.copy_from_slice(&master[..32]);
+    chain_code.copy_from_slice(&master[32..]);
+
+    for &index in path {
+        let hardened_index = index | 0x8000_0000;
+        let mut data = Vec::with_capacity(37);
+        data.push(0u8);
+        data.extend_from_slice(&key);
+        data.extend_from_slice(&hardened_index.to_be_bytes());
+
+        let derived = hmac_sha512(&chain_code, &data);
+        key.copy_from_slice(&derived[..32]);
+        chain_code.copy_from_slice(&derived[32..]);
+    }
+
+    (key, chain_code)
+}
+
 // --- Generic API Response Structures ---
 
 #[derive(Serialize)]
@@ -30,6 +100,24 @@ fn error_response(msg: &str) -> HttpResponse {
     })
 }
 
+// Converts a Solana `Instruction` into the JSON-friendly `InstructionResponse`
+// shape shared by every instruction-building endpoint.
+fn instruction_to_response(instruction: &Instruction) -> InstructionResponse {
+    InstructionResponse {
+        program_id: instruction.program_id.to_string(),
+        accounts: instruction
+            .accounts
+            .iter()
+            .map(|acc| AccountInfo {
+                pubkey: acc.pubkey.to_string(),
+                is_signer: acc.is_signer,
+                is_writable: acc.is_writable,
+            })
+            .collect(),
+        instruction_data: general_purpose::STANDARD.encode(&instruction.data),
+    }
+}
+
 // --- 1. Generate Keypair Endpoint ---
 
 #[derive(Serialize)]
@@ -64,6 +152,8 @@ struct CreateTokenRequest {
     mint_authority: String,
     mint: String,
     decimals: u8,
+    #[serde(default, rename = "freezeAuthority")]
+    freeze_authority: Option<String>,
 }
 
 #[derive(Serialize)]
@@ -82,7 +172,7 @@ struct InstructionResponse {
 }
 
 /// Handles POST /token/create
-/// Creates an SPL Token `InitializeMint` instruction.
+/// Creates an SPL Token `InitializeMint2` instruction.
 async fn create_token(req: web::Json<CreateTokenRequest>) -> impl Responder {
     // Parse mint authority public key from request
     let mint_authority_pubkey = match Pubkey::from_str(&req.mint_authority) {
@@ -96,12 +186,23 @@ async fn create_token(req: web::Json<CreateTokenRequest>) -> impl Responder {
         Err(_) => return error_response("Invalid base58 string for mint."),
     };
 
-    // Create the `InitializeMint` instruction
-    let instruction = match token_instruction::initialize_mint(
+    // Parse the optional freeze authority public key from request
+    let freeze_authority_pubkey = match &req.freeze_authority {
+        Some(freeze_authority) => match Pubkey::from_str(freeze_authority) {
+            Ok(pubkey) => Some(pubkey),
+            Err(_) => return error_response("Invalid base58 string for freezeAuthority."),
+        },
+        None => None,
+    };
+
+    // Create the `InitializeMint2` instruction. Unlike `initialize_mint`,
+    // this variant doesn't require the rent sysvar account. The optional
+    // freeze authority lets mints be frozen/thawed later.
+    let instruction = match token_instruction::initialize_mint2(
         &spl_token::id(),
         &mint_pubkey,
         &mint_authority_pubkey,
-        None, // No freeze authority
+        freeze_authority_pubkey.as_ref(),
         req.decimals,
     ) {
         Ok(inst) => inst,
@@ -202,7 +303,904 @@ async fn mint_token(req: web::Json<MintTokenRequest>) -> impl Responder {
     })
 }
 
-// --- 4. Health Check Endpoint ---
+// --- 4. Associated Token Account Endpoint ---
+
+#[derive(Deserialize)]
+struct AssociatedTokenAccountRequest {
+    owner: String,
+    mint: String,
+}
+
+/// Handles POST /token/associated
+/// Derives the associated token account (ATA) for an owner/mint pair and
+/// returns the `create_associated_token_account` instruction needed to
+/// initialize it on-chain.
+async fn create_associated_token_account_endpoint(
+    req: web::Json<AssociatedTokenAccountRequest>,
+) -> impl Responder {
+    // Parse owner public key from request
+    let owner_pubkey = match Pubkey::from_str(&req.owner) {
+        Ok(pubkey) => pubkey,
+        Err(_) => return error_response("Invalid base58 string for owner."),
+    };
+
+    // Parse mint account public key from request
+    let mint_pubkey = match Pubkey::from_str(&req.mint) {
+        Ok(pubkey) => pubkey,
+        Err(_) => return error_response("Invalid base58 string for mint."),
+    };
+
+    // The owner pays for and controls their own associated token account
+    let instruction = create_associated_token_account(
+        &owner_pubkey,
+        &owner_pubkey,
+        &mint_pubkey,
+        &spl_token::id(),
+    );
+
+    HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        data: Some(instruction_to_response(&instruction)),
+        error: None,
+    })
+}
+
+// --- 5. Send Token Endpoint ---
+
+#[derive(Deserialize)]
+struct SendTokenRequest {
+    mint: String,
+    source: String,
+    destination: String,
+    authority: String,
+    amount: u64,
+    decimals: u8,
+}
+
+/// Handles POST /send/token
+/// Builds the standard two-instruction SPL transfer flow: idempotently
+/// creating the recipient's associated token account (a no-op if it
+/// already exists) followed by a `transfer_checked` instruction moving
+/// the tokens into it.
+async fn send_token(req: web::Json<SendTokenRequest>) -> impl Responder {
+    // Parse mint public key
+    let mint_pubkey = match Pubkey::from_str(&req.mint) {
+        Ok(pubkey) => pubkey,
+        Err(_) => return error_response("Invalid base58 string for mint."),
+    };
+
+    // Parse source token account public key
+    let source_pubkey = match Pubkey::from_str(&req.source) {
+        Ok(pubkey) => pubkey,
+        Err(_) => return error_response("Invalid base58 string for source."),
+    };
+
+    // Parse destination owner public key
+    let destination_pubkey = match Pubkey::from_str(&req.destination) {
+        Ok(pubkey) => pubkey,
+        Err(_) => return error_response("Invalid base58 string for destination."),
+    };
+
+    // Parse transfer authority public key
+    let authority_pubkey = match Pubkey::from_str(&req.authority) {
+        Ok(pubkey) => pubkey,
+        Err(_) => return error_response("Invalid base58 string for authority."),
+    };
+
+    // The destination's ATA is the canonical recipient account for the transfer
+    let destination_ata = get_associated_token_address(&destination_pubkey, &mint_pubkey);
+
+    // Idempotent so repeat transfers to an already-initialized ATA don't
+    // abort with "account already in use" before the transfer even runs
+    let create_ata_instruction = create_associated_token_account_idempotent(
+        &authority_pubkey,
+        &destination_pubkey,
+        &mint_pubkey,
+        &spl_token::id(),
+    );
+
+    let transfer_instruction = match token_instruction::transfer_checked(
+        &spl_token::id(),
+        &source_pubkey,
+        &mint_pubkey,
+        &destination_ata,
+        &authority_pubkey,
+        &[], // No multisig signers
+        req.amount,
+        req.decimals,
+    ) {
+        Ok(inst) => inst,
+        Err(e) => return error_response(&format!("Failed to create transfer instruction: {}", e)),
+    };
+
+    let response_data = vec![
+        instruction_to_response(&create_ata_instruction),
+        instruction_to_response(&transfer_instruction),
+    ];
+
+    HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        data: Some(response_data),
+        error: None,
+    })
+}
+
+// --- 6. Restore Keypair From Mnemonic Endpoint ---
+
+#[derive(Deserialize)]
+struct RestoreKeypairRequest {
+    mnemonic: String,
+    passphrase: Option<String>,
+    #[serde(default, rename = "derivationPath")]
+    derivation_path: Option<String>,
+}
+
+/// Handles POST /keypair/restore
+/// Deterministically restores a Solana keypair from a BIP39 mnemonic using
+/// SLIP-0010 ed25519 derivation over the given (or default) path.
+async fn restore_keypair(req: web::Json<RestoreKeypairRequest>) -> impl Responder {
+    let mnemonic = match Mnemonic::parse(&req.mnemonic) {
+        Ok(m) => m,
+        Err(_) => return error_response("Invalid BIP39 mnemonic phrase."),
+    };
+
+    let path_str = req
+        .derivation_path
+        .as_deref()
+        .unwrap_or(DEFAULT_DERIVATION_PATH);
+    let path = match parse_derivation_path(path_str) {
+        Ok(p) => p,
+        Err(e) => return error_response(&e),
+    };
+
+    let passphrase = req.passphrase.as_deref().unwrap_or("");
+    let seed = mnemonic.to_seed(passphrase);
+    let (secret_key_bytes, _) = derive_ed25519_slip10(&seed, &path);
+
+    let keypair = match Keypair::from_seed(&secret_key_bytes) {
+        Ok(kp) => kp,
+        Err(e) => return error_response(&format!("Failed to derive keypair: {}", e)),
+    };
+
+    HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        data: Some(KeypairResponse {
+            pubkey: keypair.pubkey().to_string(),
+            secret: bs58::encode(&keypair.to_bytes()[..32]).into_string(),
+        }),
+        error: None,
+    })
+}
+
+// --- 7. Generate Mnemonic Keypair Endpoint ---
+
+#[derive(Deserialize)]
+struct GenerateMnemonicRequest {
+    #[serde(default, rename = "wordCount")]
+    word_count: Option<usize>,
+}
+
+#[derive(Serialize)]
+struct MnemonicKeypairResponse {
+    pubkey: String,
+    secret: String,
+    mnemonic: String,
+}
+
+/// Handles POST /keypair/mnemonic
+/// Generates a fresh BIP39 mnemonic (12 words by default, or 24 if
+/// requested) alongside the Solana keypair it derives via the same
+/// SLIP-0010 path used by `/keypair/restore`.
+async fn generate_mnemonic_keypair(req: web::Json<GenerateMnemonicRequest>) -> impl Responder {
+    let word_count = req.word_count.unwrap_or(12);
+    if word_count != 12 && word_count != 24 {
+        return error_response("wordCount must be 12 or 24.");
+    }
+
+    let mnemonic = match Mnemonic::generate(word_count) {
+        Ok(m) => m,
+        Err(e) => return error_response(&format!("Failed to generate mnemonic: {}", e)),
+    };
+
+    let seed = mnemonic.to_seed("");
+    let path = parse_derivation_path(DEFAULT_DERIVATION_PATH).expect("default path is valid");
+    let (secret_key_bytes, _) = derive_ed25519_slip10(&seed, &path);
+
+    let keypair = match Keypair::from_seed(&secret_key_bytes) {
+        Ok(kp) => kp,
+        Err(e) => return error_response(&format!("Failed to derive keypair: {}", e)),
+    };
+
+    HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        data: Some(MnemonicKeypairResponse {
+            pubkey: keypair.pubkey().to_string(),
+            secret: bs58::encode(&keypair.to_bytes()[..32]).into_string(),
+            mnemonic: mnemonic.to_string(),
+        }),
+        error: None,
+    })
+}
+
+// --- 8. NFT Metadata Creation Endpoint ---
+
+#[derive(Deserialize)]
+struct CreatorInput {
+    address: String,
+    verified: bool,
+    share: u8,
+}
+
+#[derive(Deserialize)]
+struct CreateNftMetadataRequest {
+    mint: String,
+    #[serde(rename = "mintAuthority")]
+    mint_authority: String,
+    #[serde(rename = "updateAuthority")]
+    update_authority: String,
+    payer: String,
+    name: String,
+    symbol: String,
+    uri: String,
+    #[serde(rename = "sellerFeeBasisPoints")]
+    seller_fee_basis_points: u16,
+    #[serde(default)]
+    creators: Option<Vec<CreatorInput>>,
+}
+
+/// Handles POST /nft/create
+/// Derives the Metaplex metadata PDA for a mint and returns the
+/// `CreateMetadataAccountV3` instruction that attaches name/symbol/URI
+/// metadata to it.
+async fn create_nft_metadata(req: web::Json<CreateNftMetadataRequest>) -> impl Responder {
+    // Parse mint public key
+    let mint_pubkey = match Pubkey::from_str(&req.mint) {
+        Ok(pubkey) => pubkey,
+        Err(_) => return error_response("Invalid base58 string for mint."),
+    };
+
+    // Parse mint authority public key
+    let mint_authority_pubkey = match Pubkey::from_str(&req.mint_authority) {
+        Ok(pubkey) => pubkey,
+        Err(_) => return error_response("Invalid base58 string for mintAuthority."),
+    };
+
+    // Parse update authority public key
+    let update_authority_pubkey = match Pubkey::from_str(&req.update_authority) {
+        Ok(pubkey) => pubkey,
+        Err(_) => return error_response("Invalid base58 string for updateAuthority."),
+    };
+
+    // Parse payer public key
+    let payer_pubkey = match Pubkey::from_str(&req.payer) {
+        Ok(pubkey) => pubkey,
+        Err(_) => return error_response("Invalid base58 string for payer."),
+    };
+
+    // Parse the optional creators list
+    let creators = match &req.creators {
+        Some(list) => {
+            let mut parsed = Vec::with_capacity(list.len());
+            for creator in list {
+                let address = match Pubkey::from_str(&creator.address) {
+                    Ok(pubkey) => pubkey,
+                    Err(_) => return error_response("Invalid base58 string for creator address."),
+                };
+                parsed.push(Creator {
+                    address,
+                    verified: creator.verified,
+                    share: creator.share,
+                });
+            }
+            Some(parsed)
+        }
+        None => None,
+    };
+
+    // Derive the metadata PDA: ["metadata", token_metadata_program_id, mint]
+    let (metadata_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"metadata",
+            mpl_token_metadata::id().as_ref(),
+            mint_pubkey.as_ref(),
+        ],
+        &mpl_token_metadata::id(),
+    );
+
+    let instruction = mpl_token_metadata::instruction::create_metadata_accounts_v3(
+        mpl_token_metadata::id(),
+        metadata_pda,
+        mint_pubkey,
+        mint_authority_pubkey,
+        payer_pubkey,
+        update_authority_pubkey,
+        req.name.clone(),
+        req.symbol.clone(),
+        req.uri.clone(),
+        creators,
+        req.seller_fee_basis_points,
+        true,
+        true,
+        None,
+        None,
+        None,
+    );
+
+    HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        data: Some(instruction_to_response(&instruction)),
+        error: None,
+    })
+}
+
+// --- 9. RPC Client Configuration ---
+
+// Resolves the cluster RPC endpoint to use for the `/tx/simulate` and
+// `/tx/send` endpoints. `RPC_URL` takes precedence when set; otherwise the
+// `CLUSTER` env var selects a well-known devnet/testnet/mainnet endpoint,
+// defaulting to devnet.
+fn cluster_rpc_url() -> String {
+    if let Ok(url) = env::var("RPC_URL") {
+        return url;
+    }
+
+    match env::var("CLUSTER").as_deref() {
+        Ok("mainnet") | Ok("mainnet-beta") => "https://api.mainnet-beta.solana.com".to_string(),
+        Ok("testnet") => "https://api.testnet.solana.com".to_string(),
+        _ => "https://api.devnet.solana.com".to_string(),
+    }
+}
+
+// Whether the configured cluster is mainnet. `RPC_URL` is matched against
+// the well-known mainnet hostname since it can point anywhere; `CLUSTER`
+// is matched directly.
+fn is_mainnet_cluster() -> bool {
+    if let Ok(url) = env::var("RPC_URL") {
+        return url.contains("mainnet-beta.solana.com");
+    }
+
+    matches!(
+        env::var("CLUSTER").as_deref(),
+        Ok("mainnet") | Ok("mainnet-beta")
+    )
+}
+
+#[derive(Deserialize)]
+struct AccountMetaInput {
+    pubkey: String,
+    #[serde(rename = "isSigner")]
+    is_signer: bool,
+    #[serde(rename = "isWritable")]
+    is_writable: bool,
+}
+
+#[derive(Deserialize)]
+struct InstructionInput {
+    #[serde(rename = "programId")]
+    program_id: String,
+    accounts: Vec<AccountMetaInput>,
+    #[serde(rename = "instructionData")]
+    instruction_data: String,
+}
+
+// Decodes the client-supplied instruction payloads used by the RPC
+// endpoints back into `Instruction`s.
+fn instructions_from_input(inputs: &[InstructionInput]) -> Result<Vec<Instruction>, String> {
+    inputs
+        .iter()
+        .map(|input| {
+            let program_id = Pubkey::from_str(&input.program_id)
+                .map_err(|_| "Invalid base58 string for programId.".to_string())?;
+
+            let accounts = input
+                .accounts
+                .iter()
+                .map(|acc| {
+                    Pubkey::from_str(&acc.pubkey)
+                        .map(|pubkey| AccountMeta {
+                            pubkey,
+                            is_signer: acc.is_signer,
+                            is_writable: acc.is_writable,
+                        })
+                        .map_err(|_| "Invalid base58 string for account pubkey.".to_string())
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let data = general_purpose::STANDARD
+                .decode(&input.instruction_data)
+                .map_err(|_| "Invalid base64 string for instructionData.".to_string())?;
+
+            Ok(Instruction {
+                program_id,
+                accounts,
+                data,
+            })
+        })
+        .collect()
+}
+
+// --- 10. Transaction Simulation Endpoint ---
+
+#[derive(Deserialize)]
+struct SimulateTransactionRequest {
+    instructions: Vec<InstructionInput>,
+    #[serde(rename = "feePayer")]
+    fee_payer: String,
+}
+
+#[derive(Serialize)]
+struct SimulateTransactionResponse {
+    logs: Vec<String>,
+    #[serde(rename = "unitsConsumed")]
+    units_consumed: Option<u64>,
+    err: Option<String>,
+}
+
+/// Handles POST /tx/simulate
+/// Packages the supplied instructions into a transaction with a freshly
+/// fetched recent blockhash and simulates it against the configured
+/// cluster, without requiring signatures.
+async fn simulate_transaction(req: web::Json<SimulateTransactionRequest>) -> impl Responder {
+    let fee_payer_pubkey = match Pubkey::from_str(&req.fee_payer) {
+        Ok(pubkey) => pubkey,
+        Err(_) => return error_response("Invalid base58 string for feePayer."),
+    };
+
+    let instructions = match instructions_from_input(&req.instructions) {
+        Ok(instructions) => instructions,
+        Err(e) => return error_response(&e),
+    };
+
+    let client = RpcClient::new(cluster_rpc_url());
+
+    let recent_blockhash = match client.get_latest_blockhash().await {
+        Ok(hash) => hash,
+        Err(e) => return error_response(&format!("Failed to fetch recent blockhash: {}", e)),
+    };
+
+    let message =
+        Message::new_with_blockhash(&instructions, Some(&fee_payer_pubkey), &recent_blockhash);
+    let transaction = Transaction::new_unsigned(message);
+
+    let config = RpcSimulateTransactionConfig {
+        sig_verify: false,
+        ..Default::default()
+    };
+
+    match client
+        .simulate_transaction_with_config(&transaction, config)
+        .await
+    {
+        Ok(response) => {
+            let value = response.value;
+            HttpResponse::Ok().json(ApiResponse {
+                success: true,
+                data: Some(SimulateTransactionResponse {
+                    logs: value.logs.unwrap_or_default(),
+                    units_consumed: value.units_consumed,
+                    err: value.err.map(|e| e.to_string()),
+                }),
+                error: None,
+            })
+        }
+        Err(e) => error_response(&format!("Simulation failed: {}", e)),
+    }
+}
+
+// --- 11. Transaction Submission Endpoint ---
+
+#[derive(Deserialize)]
+struct SendTransactionRequest {
+    instructions: Vec<InstructionInput>,
+    #[serde(rename = "secretKeys")]
+    secret_keys: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct SendTransactionResponse {
+    signature: String,
+}
+
+/// Handles POST /tx/send
+/// Signs the supplied instructions with the provided secret keys (the
+/// first entry pays transaction fees) and submits the resulting
+/// transaction to the configured cluster, returning its signature once it
+/// lands.
+///
+/// Unlike every other endpoint in this file, this one takes custody of raw
+/// secret keys for the lifetime of the request (in process memory and
+/// potentially in request logs) instead of only returning unsigned
+/// instruction bytes for the caller to sign locally. Treat it as
+/// higher-trust and prefer a dedicated signing client where possible. As a
+/// backstop, it refuses to run against mainnet unless
+/// `ALLOW_MAINNET_SEND=true` is explicitly set.
+async fn send_transaction(req: web::Json<SendTransactionRequest>) -> impl Responder {
+    if is_mainnet_cluster() && env::var("ALLOW_MAINNET_SEND").as_deref() != Ok("true") {
+        return error_response(
+            "Refusing to sign and send with raw secret keys against mainnet. \
+             Set ALLOW_MAINNET_SEND=true to opt in.",
+        );
+    }
+
+    let instructions = match instructions_from_input(&req.instructions) {
+        Ok(instructions) => instructions,
+        Err(e) => return error_response(&e),
+    };
+
+    if req.secret_keys.is_empty() {
+        return error_response("At least one secret key is required to pay fees.");
+    }
+
+    let mut keypairs = Vec::with_capacity(req.secret_keys.len());
+    for secret in &req.secret_keys {
+        let secret_bytes = match bs58::decode(secret).into_vec() {
+            Ok(bytes) => bytes,
+            Err(_) => return error_response("Invalid base58 string for a secret key."),
+        };
+        let keypair = match Keypair::from_seed(&secret_bytes) {
+            Ok(kp) => kp,
+            Err(e) => return error_response(&format!("Invalid secret key: {}", e)),
+        };
+        keypairs.push(keypair);
+    }
+
+    let fee_payer_pubkey = keypairs[0].pubkey();
+    let signer_refs: Vec<&Keypair> = keypairs.iter().collect();
+
+    let client = RpcClient::new(cluster_rpc_url());
+
+    let recent_blockhash = match client.get_latest_blockhash().await {
+        Ok(hash) => hash,
+        Err(e) => return error_response(&format!("Failed to fetch recent blockhash: {}", e)),
+    };
+
+    let message =
+        Message::new_with_blockhash(&instructions, Some(&fee_payer_pubkey), &recent_blockhash);
+    let mut transaction = Transaction::new_unsigned(message);
+
+    // `Transaction::sign` panics on a signer mismatch instead of returning a
+    // `Result`; use `try_sign` so a missing/extra/misordered secret key is
+    // reported as a 400 like every other invalid-input case in this file.
+    if let Err(e) = transaction.try_sign(&signer_refs, recent_blockhash) {
+        return error_response(&format!("Failed to sign transaction: {}", e));
+    }
+
+    match client.send_and_confirm_transaction(&transaction).await {
+        Ok(signature) => HttpResponse::Ok().json(ApiResponse {
+            success: true,
+            data: Some(SendTransactionResponse {
+                signature: signature.to_string(),
+            }),
+            error: None,
+        }),
+        Err(e) => error_response(&format!("Failed to send transaction: {}", e)),
+    }
+}
+
+// --- 12. Collection Authority Approve Endpoint ---
+
+#[derive(Deserialize)]
+struct ApproveCollectionAuthorityRequest {
+    mint: String,
+    metadata: String,
+    #[serde(rename = "newAuthority")]
+    new_authority: String,
+    #[serde(rename = "updateAuthority")]
+    update_authority: String,
+    payer: String,
+}
+
+// Derives the collection authority record PDA:
+// ["metadata", token_metadata_program_id, mint, "collection_authority", authority]
+fn collection_authority_record_pda(mint: &Pubkey, authority: &Pubkey) -> Pubkey {
+    let program_id = mpl_token_metadata::id();
+    let (pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"metadata",
+            program_id.as_ref(),
+            mint.as_ref(),
+            b"collection_authority",
+            authority.as_ref(),
+        ],
+        &program_id,
+    );
+    pda
+}
+
+/// Handles POST /nft/collection/approve
+/// Derives the collection authority record PDA for a new delegate and
+/// returns the `approve_collection_authority` instruction that lets the
+/// delegate verify NFTs into the collection without holding the update
+/// authority key.
+async fn approve_collection_authority_endpoint(
+    req: web::Json<ApproveCollectionAuthorityRequest>,
+) -> impl Responder {
+    // Parse mint public key
+    let mint_pubkey = match Pubkey::from_str(&req.mint) {
+        Ok(pubkey) => pubkey,
+        Err(_) => return error_response("Invalid base58 string for mint."),
+    };
+
+    // Parse metadata PDA public key
+    let metadata_pubkey = match Pubkey::from_str(&req.metadata) {
+        Ok(pubkey) => pubkey,
+        Err(_) => return error_response("Invalid base58 string for metadata."),
+    };
+
+    // Parse the new collection authority public key
+    let new_authority_pubkey = match Pubkey::from_str(&req.new_authority) {
+        Ok(pubkey) => pubkey,
+        Err(_) => return error_response("Invalid base58 string for newAuthority."),
+    };
+
+    // Parse update authority public key
+    let update_authority_pubkey = match Pubkey::from_str(&req.update_authority) {
+        Ok(pubkey) => pubkey,
+        Err(_) => return error_response("Invalid base58 string for updateAuthority."),
+    };
+
+    // Parse payer public key
+    let payer_pubkey = match Pubkey::from_str(&req.payer) {
+        Ok(pubkey) => pubkey,
+        Err(_) => return error_response("Invalid base58 string for payer."),
+    };
+
+    let collection_authority_record =
+        collection_authority_record_pda(&mint_pubkey, &new_authority_pubkey);
+
+    let instruction = mpl_token_metadata::instruction::approve_collection_authority(
+        mpl_token_metadata::id(),
+        collection_authority_record,
+        new_authority_pubkey,
+        update_authority_pubkey,
+        payer_pubkey,
+        metadata_pubkey,
+        mint_pubkey,
+    );
+
+    HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        data: Some(instruction_to_response(&instruction)),
+        error: None,
+    })
+}
+
+// --- 13. Collection Authority Revoke Endpoint ---
+
+#[derive(Deserialize)]
+struct RevokeCollectionAuthorityRequest {
+    mint: String,
+    metadata: String,
+    #[serde(rename = "delegateAuthority")]
+    delegate_authority: String,
+    #[serde(rename = "updateAuthority")]
+    update_authority: String,
+}
+
+/// Handles POST /nft/collection/revoke
+/// Derives the collection authority record PDA for an existing delegate
+/// and returns the `revoke_collection_authority` instruction that removes
+/// its verification privileges over the collection.
+async fn revoke_collection_authority_endpoint(
+    req: web::Json<RevokeCollectionAuthorityRequest>,
+) -> impl Responder {
+    // Parse mint public key
+    let mint_pubkey = match Pubkey::from_str(&req.mint) {
+        Ok(pubkey) => pubkey,
+        Err(_) => return error_response("Invalid base58 string for mint."),
+    };
+
+    // Parse metadata PDA public key
+    let metadata_pubkey = match Pubkey::from_str(&req.metadata) {
+        Ok(pubkey) => pubkey,
+        Err(_) => return error_response("Invalid base58 string for metadata."),
+    };
+
+    // Parse the existing delegate authority public key
+    let delegate_authority_pubkey = match Pubkey::from_str(&req.delegate_authority) {
+        Ok(pubkey) => pubkey,
+        Err(_) => return error_response("Invalid base58 string for delegateAuthority."),
+    };
+
+    // Parse update authority public key
+    let update_authority_pubkey = match Pubkey::from_str(&req.update_authority) {
+        Ok(pubkey) => pubkey,
+        Err(_) => return error_response("Invalid base58 string for updateAuthority."),
+    };
+
+    let collection_authority_record =
+        collection_authority_record_pda(&mint_pubkey, &delegate_authority_pubkey);
+
+    let instruction = mpl_token_metadata::instruction::revoke_collection_authority(
+        mpl_token_metadata::id(),
+        collection_authority_record,
+        delegate_authority_pubkey,
+        update_authority_pubkey,
+        metadata_pubkey,
+        mint_pubkey,
+    );
+
+    HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        data: Some(instruction_to_response(&instruction)),
+        error: None,
+    })
+}
+
+// --- 14. Set Authority Endpoint ---
+
+#[derive(Deserialize)]
+struct SetAuthorityRequest {
+    mint: String,
+    #[serde(rename = "currentAuthority")]
+    current_authority: String,
+    #[serde(default, rename = "newAuthority")]
+    new_authority: Option<String>,
+    #[serde(rename = "authorityType")]
+    authority_type: String,
+}
+
+// Parses the JSON-friendly authority type name into the SPL Token
+// `AuthorityType` enum.
+fn parse_authority_type(raw: &str) -> Result<AuthorityType, String> {
+    match raw {
+        "mintTokens" => Ok(AuthorityType::MintTokens),
+        "freezeAccount" => Ok(AuthorityType::FreezeAccount),
+        "accountOwner" => Ok(AuthorityType::AccountOwner),
+        "closeAccount" => Ok(AuthorityType::CloseAccount),
+        other => Err(format!("Unknown authorityType: {}", other)),
+    }
+}
+
+/// Handles POST /token/set-authority
+/// Creates an SPL Token `SetAuthority` instruction, transferring or
+/// revoking (when `newAuthority` is omitted) a mint's authority of the
+/// given type.
+async fn set_authority(req: web::Json<SetAuthorityRequest>) -> impl Responder {
+    // Parse mint public key
+    let mint_pubkey = match Pubkey::from_str(&req.mint) {
+        Ok(pubkey) => pubkey,
+        Err(_) => return error_response("Invalid base58 string for mint."),
+    };
+
+    // Parse current authority public key
+    let current_authority_pubkey = match Pubkey::from_str(&req.current_authority) {
+        Ok(pubkey) => pubkey,
+        Err(_) => return error_response("Invalid base58 string for currentAuthority."),
+    };
+
+    // Parse the optional new authority public key
+    let new_authority_pubkey = match &req.new_authority {
+        Some(new_authority) => match Pubkey::from_str(new_authority) {
+            Ok(pubkey) => Some(pubkey),
+            Err(_) => return error_response("Invalid base58 string for newAuthority."),
+        },
+        None => None,
+    };
+
+    let authority_type = match parse_authority_type(&req.authority_type) {
+        Ok(authority_type) => authority_type,
+        Err(e) => return error_response(&e),
+    };
+
+    let instruction = match token_instruction::set_authority(
+        &spl_token::id(),
+        &mint_pubkey,
+        new_authority_pubkey.as_ref(),
+        authority_type,
+        &current_authority_pubkey,
+        &[], // No multisig signers
+    ) {
+        Ok(inst) => inst,
+        Err(e) => {
+            return error_response(&format!("Failed to create set-authority instruction: {}", e))
+        }
+    };
+
+    HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        data: Some(instruction_to_response(&instruction)),
+        error: None,
+    })
+}
+
+// --- 15. Freeze Account Endpoint ---
+
+#[derive(Deserialize)]
+struct FreezeAccountRequest {
+    account: String,
+    mint: String,
+    authority: String,
+}
+
+/// Handles POST /token/freeze
+/// Creates an SPL Token `FreezeAccount` instruction.
+async fn freeze_account(req: web::Json<FreezeAccountRequest>) -> impl Responder {
+    // Parse token account public key
+    let account_pubkey = match Pubkey::from_str(&req.account) {
+        Ok(pubkey) => pubkey,
+        Err(_) => return error_response("Invalid base58 string for account."),
+    };
+
+    // Parse mint public key
+    let mint_pubkey = match Pubkey::from_str(&req.mint) {
+        Ok(pubkey) => pubkey,
+        Err(_) => return error_response("Invalid base58 string for mint."),
+    };
+
+    // Parse freeze authority public key
+    let authority_pubkey = match Pubkey::from_str(&req.authority) {
+        Ok(pubkey) => pubkey,
+        Err(_) => return error_response("Invalid base58 string for authority."),
+    };
+
+    let instruction = match token_instruction::freeze_account(
+        &spl_token::id(),
+        &account_pubkey,
+        &mint_pubkey,
+        &authority_pubkey,
+        &[], // No multisig signers
+    ) {
+        Ok(inst) => inst,
+        Err(e) => return error_response(&format!("Failed to create freeze instruction: {}", e)),
+    };
+
+    HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        data: Some(instruction_to_response(&instruction)),
+        error: None,
+    })
+}
+
+// --- 16. Thaw Account Endpoint ---
+
+#[derive(Deserialize)]
+struct ThawAccountRequest {
+    account: String,
+    mint: String,
+    authority: String,
+}
+
+/// Handles POST /token/thaw
+/// Creates an SPL Token `ThawAccount` instruction.
+async fn thaw_account(req: web::Json<ThawAccountRequest>) -> impl Responder {
+    // Parse token account public key
+    let account_pubkey = match Pubkey::from_str(&req.account) {
+        Ok(pubkey) => pubkey,
+        Err(_) => return error_response("Invalid base58 string for account."),
+    };
+
+    // Parse mint public key
+    let mint_pubkey = match Pubkey::from_str(&req.mint) {
+        Ok(pubkey) => pubkey,
+        Err(_) => return error_response("Invalid base58 string for mint."),
+    };
+
+    // Parse freeze authority public key
+    let authority_pubkey = match Pubkey::from_str(&req.authority) {
+        Ok(pubkey) => pubkey,
+        Err(_) => return error_response("Invalid base58 string for authority."),
+    };
+
+    let instruction = match token_instruction::thaw_account(
+        &spl_token::id(),
+        &account_pubkey,
+        &mint_pubkey,
+        &authority_pubkey,
+        &[], // No multisig signers
+    ) {
+        Ok(inst) => inst,
+        Err(e) => return error_response(&format!("Failed to create thaw instruction: {}", e)),
+    };
+
+    HttpResponse::Ok().json(ApiResponse {
+        success: true,
+        data: Some(instruction_to_response(&instruction)),
+        error: None,
+    })
+}
+
+// --- 17. Health Check Endpoint ---
 
 /// Handles GET /health
 /// Helps Render detect the open port.
@@ -225,6 +1223,30 @@ async fn main() -> std::io::Result<()> {
             .route("/keypair", web::post().to(generate_keypair))
             .route("/token/create", web::post().to(create_token))
             .route("/token/mint", web::post().to(mint_token))
+            .route(
+                "/token/associated",
+                web::post().to(create_associated_token_account_endpoint),
+            )
+            .route("/send/token", web::post().to(send_token))
+            .route("/keypair/restore", web::post().to(restore_keypair))
+            .route(
+                "/keypair/mnemonic",
+                web::post().to(generate_mnemonic_keypair),
+            )
+            .route("/nft/create", web::post().to(create_nft_metadata))
+            .route("/tx/simulate", web::post().to(simulate_transaction))
+            .route("/tx/send", web::post().to(send_transaction))
+            .route(
+                "/nft/collection/approve",
+                web::post().to(approve_collection_authority_endpoint),
+            )
+            .route(
+                "/nft/collection/revoke",
+                web::post().to(revoke_collection_authority_endpoint),
+            )
+            .route("/token/set-authority", web::post().to(set_authority))
+            .route("/token/freeze", web::post().to(freeze_account))
+            .route("/token/thaw", web::post().to(thaw_account))
             .route("/health", web::get().to(health))
     })
     .bind(&bind_address) {